@@ -1,3 +1,4 @@
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::{io, time};
 
 use chrono::{DateTime, Local};
@@ -6,8 +7,18 @@ use crossterm::{event, terminal};
 use notify_rust::Notification;
 use ratatui::{prelude::*, widgets::*};
 
+#[cfg(feature = "sound")]
+mod sound;
+
+/// Set by the SIGINT/SIGTERM handler and polled once per loop iteration in
+/// `run_phase`, so a Ctrl-C mid-run still unwinds through `main` and restores
+/// the terminal instead of leaving it in raw/alternate-screen mode.
+pub(crate) static SHOULD_QUIT: AtomicBool = AtomicBool::new(false);
+
 fn main() -> io::Result<()> {
     let cli = Cli::parse();
+    ctrlc::set_handler(|| SHOULD_QUIT.store(true, Ordering::SeqCst))
+        .expect("Failed to register signal handler");
     let mut terminal = Terminal::new(CrosstermBackend::new(io::stdout()))?;
     initialize(&mut terminal)?;
     let app_result = run_timer(cli, &mut terminal);
@@ -39,22 +50,27 @@ type AppError = Result<(), Error>;
 #[derive(Parser)]
 #[command(version, about)]
 pub struct Cli {
-    #[clap(help = "The duration of the timer. You can use the following formats:
-    - h (hours), 
+    #[clap(
+        required_unless_present_any = ["pomodoro", "count_up"],
+        help = "The duration of the timer. You can use the following formats:
+    - h (hours),
     - m (minutes)
     - s (seconds)
     - ms (milliseconds).
-    
+
     If no unit is provided, seconds will be used.
     Examples:
     - timer 50 -> Runs a timer for 50 seconds (default).
     - timer 45m -> Runs a timer for 45 minutes.
-    - timer 1h30m -> Runs a timer for 1 hour and 30 minutes.")]
-    duration: String,
+    - timer 1h30m -> Runs a timer for 1 hour and 30 minutes.
+    - timer up -> Counts up with no target (stopwatch mode).
+    - timer \"10m tea, 5m steep, 2m cool\" -> Runs labeled segments back-to-back."
+    )]
+    duration: Option<String>,
     #[arg(short, long, help = "A name for the timer.")]
     name: Option<String>,
     #[arg(
-        long, help = "Send a notification when the timer begins and ends.", 
+        long, help = "Send a notification when the timer begins and ends.",
         default_value = "true", action = clap::ArgAction::Set,
         require_equals(true)
     )]
@@ -67,6 +83,60 @@ pub struct Cli {
         value_parser = clap::builder::PossibleValuesParser::new(&["24h", "12h"])
     )]
     format: String,
+    #[arg(
+        long,
+        help = "Run a Pomodoro session: --cycles rounds of work/break (4 by default), then a long break."
+    )]
+    pomodoro: bool,
+    #[arg(
+        long,
+        help = "Duration of a Pomodoro work phase.",
+        default_value = "25m",
+        requires = "pomodoro"
+    )]
+    work: String,
+    #[arg(
+        long = "break",
+        help = "Duration of a Pomodoro short break.",
+        default_value = "5m",
+        requires = "pomodoro"
+    )]
+    short_break: String,
+    #[arg(
+        long,
+        help = "Duration of the Pomodoro long break, taken after the last cycle.",
+        default_value = "15m",
+        requires = "pomodoro"
+    )]
+    long_break: String,
+    #[arg(
+        long,
+        help = "Number of work/short-break cycles before the long break.",
+        default_value_t = 4,
+        value_parser = clap::value_parser!(u32).range(1..),
+        requires = "pomodoro"
+    )]
+    cycles: u32,
+    #[arg(
+        long = "count-up",
+        help = "Count up from zero instead of counting down. If `duration` is \
+                given it's treated as a target to count up toward; otherwise \
+                the timer runs open-ended until you quit."
+    )]
+    count_up: bool,
+    #[cfg(feature = "sound")]
+    #[arg(
+        long,
+        help = "Path to an audio file to play when the timer ends. Defaults to a built-in chime."
+    )]
+    sound: Option<String>,
+    #[cfg(feature = "sound")]
+    #[arg(
+        long,
+        help = "Playback volume for --sound, from 0.0 (silent) to 1.0 (full).",
+        default_value_t = 1.0
+    )]
+    volume: f32,
 }
 
 fn initialize<B: Backend>(terminal: &mut Terminal<B>) -> io::Result<()> {
@@ -97,39 +167,166 @@ fn notify(message: &str)  {
         .sound_name(sound)
         .show();
 }
-fn run_timer<B: Backend>(cli: Cli, terminal: &mut Terminal<B>) -> AppError {
-    let name = match cli.name.clone() {
-        Some(name) => name,
-        None => "Timer".to_owned(),
-    };
-   
-    let start_time = time::Instant::now();
-    let timer_started_at = chrono::Local::now();
-    let duration = match cli.duration.parse::<u64>() {
-        Ok(duration) => time::Duration::from_secs(duration),
-        Err(_) => match humantime::parse_duration(&cli.duration) {
-            Ok(duration) => duration,
-            Err(e) => return Err(Error::UnknownUnit(e.to_string())),
-        },
-    };
-    
-    // If Cli was parsed correctly, notify the user that the timer has started
+
+fn parse_duration(input: &str) -> Result<time::Duration, Error> {
+    match input.parse::<u64>() {
+        Ok(secs) => Ok(time::Duration::from_secs(secs)),
+        Err(_) => humantime::parse_duration(input).map_err(|e| Error::UnknownUnit(e.to_string())),
+    }
+}
+
+/// Parses a `,`/`+`-separated list of `<duration> [label]` segments, e.g.
+/// `"10m tea, 5m steep, 2m cool"`. A segment with no label falls back to its
+/// duration text as the label.
+fn parse_sequence(input: &str) -> Result<Vec<(String, time::Duration)>, Error> {
+    input
+        .split([',', '+'])
+        .map(|segment| {
+            let segment = segment.trim();
+            let (duration_part, label) = match segment.split_once(char::is_whitespace) {
+                Some((duration_part, label)) => (duration_part, label.trim()),
+                None => (segment, ""),
+            };
+            let duration = parse_duration(duration_part)?;
+            let label = if label.is_empty() {
+                duration_part.to_owned()
+            } else {
+                label.to_owned()
+            };
+            Ok((label, duration))
+        })
+        .collect()
+}
+
+#[cfg(feature = "sound")]
+fn play_sound(cli: &Cli) {
+    sound::play(cli.sound.as_deref(), cli.volume);
+}
+
+#[cfg(not(feature = "sound"))]
+fn play_sound(_cli: &Cli) {}
+
+fn run_timer<B: Backend>(mut cli: Cli, terminal: &mut Terminal<B>) -> AppError {
+    if cli.duration.as_deref() == Some("up") {
+        cli.count_up = true;
+        cli.duration = None;
+    }
+
+    if cli.pomodoro {
+        return run_pomodoro(&cli, terminal);
+    }
+
+    if let Some(duration_arg) = cli.duration.clone() {
+        if duration_arg.contains(',') || duration_arg.contains('+') {
+            let segments = parse_sequence(&duration_arg)?;
+            return run_sequence(&cli, terminal, segments);
+        }
+    }
+
+    let name = cli.name.clone().unwrap_or_else(|| "Timer".to_owned());
+    let target = cli.duration.as_deref().map(parse_duration).transpose()?;
+
     if cli.notify {
         notify(&format!("{} has started.", name));
     }
+    let outcome = run_phase(terminal, &cli, target, cli.count_up, None, None)?;
+    if let PhaseOutcome::Completed = outcome {
+        if cli.notify {
+            notify(&format!("{} is over!", name));
+        }
+        play_sound(&cli);
+    }
+    Ok(())
+}
+
+/// Label shown above the progress bar for a single phase of a multi-phase run
+/// (e.g. a Pomodoro work/break cycle).
+struct PhaseLabel {
+    text: String,
+}
+
+/// Whether a phase ran to completion or was cut short by the user quitting.
+enum PhaseOutcome {
+    Completed,
+    Quit,
+}
+
+const ADD_TIME_STEP: time::Duration = time::Duration::from_secs(60);
+
+/// Tracks a phase's position within a larger multi-segment run, so the
+/// widget can show progress across the whole session rather than just the
+/// current phase.
+struct SessionProgress {
+    elapsed_before: time::Duration,
+    total: time::Duration,
+}
+
+/// Runs a single timer phase, polling for input and redrawing until `target`
+/// elapses or the user quits. A `target` of `None` means the phase runs
+/// open-ended and only ends on quit (always paired with `counting_up`).
+///
+/// Pausing is tracked as accumulated elapsed time rather than relying solely
+/// on a single `Instant::elapsed()`, since pausing must not count toward the
+/// timer: `elapsed_before_pause` holds everything accrued before the most
+/// recent pause, and `resume_instant` is reset every time the timer resumes.
+fn run_phase<B: Backend>(
+    terminal: &mut Terminal<B>,
+    cli: &Cli,
+    mut target: Option<time::Duration>,
+    counting_up: bool,
+    phase: Option<PhaseLabel>,
+    session: Option<SessionProgress>,
+) -> Result<PhaseOutcome, Error> {
+    let timer_started_at = chrono::Local::now();
+    let mut resume_instant = time::Instant::now();
+    let mut elapsed_before_pause = time::Duration::ZERO;
+    let mut paused = false;
+
+    let elapsed = |resume_instant: time::Instant, elapsed_before_pause: time::Duration, paused: bool| {
+        elapsed_before_pause + if paused { time::Duration::ZERO } else { resume_instant.elapsed() }
+    };
+
+    loop {
+        if SHOULD_QUIT.load(Ordering::SeqCst) {
+            return Ok(PhaseOutcome::Quit);
+        }
+
+        if !paused {
+            if let Some(target) = target {
+                if elapsed(resume_instant, elapsed_before_pause, paused) >= target {
+                    break;
+                }
+            }
+        }
 
-    // parse the duration from the cli
-    while start_time.elapsed() < duration {
         let event_available = event::poll(time::Duration::from_millis(20))
             .map_err(|_| Error::Terminal("Unable to poll for events".to_owned()))?;
         if event_available {
             let event =
                 event::read().map_err(|_| Error::Terminal("Unable to read events".to_owned()))?;
 
-            if let event::Event::Key(event::KeyEvent { code, .. }) = event {
+            if let event::Event::Key(event::KeyEvent { code, modifiers, .. }) = event {
                 match code {
                     event::KeyCode::Esc | event::KeyCode::Char('q') | event::KeyCode::Char('Q') => {
-                        return Ok(());
+                        return Ok(PhaseOutcome::Quit);
+                    }
+                    // Raw mode delivers Ctrl-C as a key event rather than SIGINT.
+                    event::KeyCode::Char('c') if modifiers.contains(event::KeyModifiers::CONTROL) => {
+                        return Ok(PhaseOutcome::Quit);
+                    }
+                    event::KeyCode::Char(' ') => {
+                        if paused {
+                            resume_instant = time::Instant::now();
+                        } else {
+                            elapsed_before_pause += resume_instant.elapsed();
+                        }
+                        paused = !paused;
+                    }
+                    event::KeyCode::Char('+') => {
+                        target = target.map(|t| t + ADD_TIME_STEP);
+                    }
+                    event::KeyCode::Char('-') => {
+                        target = target.map(|t| t.saturating_sub(ADD_TIME_STEP));
                     }
                     _ => (),
                 }
@@ -137,29 +334,170 @@ fn run_timer<B: Backend>(cli: Cli, terminal: &mut Terminal<B>) -> AppError {
         }
         terminal
             .draw(|f| {
-                let elapsed_time = start_time.elapsed();
-                let percent = (elapsed_time.as_secs_f32() / duration.as_secs_f32() * 100.0) as u16;
-
-                if elapsed_time > duration {
-                    return;
+                let elapsed_time = elapsed(resume_instant, elapsed_before_pause, paused);
+                if let Some(target) = target {
+                    if elapsed_time > target {
+                        return;
+                    }
                 }
-                let time_left = duration - elapsed_time;
-                draw_timer(f, percent, time_left, timer_started_at, &cli);
+                let percent = match target {
+                    Some(target) => {
+                        (elapsed_time.as_secs_f32() / target.as_secs_f32() * 100.0) as u16
+                    }
+                    None => 0,
+                };
+                let displayed = if counting_up {
+                    elapsed_time
+                } else {
+                    target.expect("countdown phases always have a target") - elapsed_time
+                };
+                let overall_percent = session.as_ref().map(|session| {
+                    let overall_elapsed = session.elapsed_before + elapsed_time;
+                    (overall_elapsed.as_secs_f32() / session.total.as_secs_f32() * 100.0) as u16
+                });
+                draw_timer(
+                    f,
+                    TimerState {
+                        percent,
+                        displayed,
+                        counting_up,
+                        paused,
+                        overall_percent,
+                    },
+                    timer_started_at,
+                    cli,
+                    phase.as_ref(),
+                );
             })
             .map_err(|_| Error::Draw("Something went very wrong.".to_owned()))?;
     }
+    Ok(PhaseOutcome::Completed)
+}
+
+/// Runs the classic Pomodoro technique: `cycles` rounds of work + short
+/// break, followed by a single long break.
+fn run_pomodoro<B: Backend>(cli: &Cli, terminal: &mut Terminal<B>) -> AppError {
+    let work = parse_duration(&cli.work)?;
+    let short_break = parse_duration(&cli.short_break)?;
+    let long_break = parse_duration(&cli.long_break)?;
+
+    for cycle in 1..=cli.cycles {
+        if cli.notify {
+            notify(&format!("Time to work (cycle {}/{}).", cycle, cli.cycles));
+        }
+        let outcome = run_phase(
+            terminal,
+            cli,
+            Some(work),
+            false,
+            Some(PhaseLabel {
+                text: format!("Work {}/{}", cycle, cli.cycles),
+            }),
+            None,
+        )?;
+        if let PhaseOutcome::Quit = outcome {
+            return Ok(());
+        }
+
+        let break_label = format!("Break {}/{}", cycle, cli.cycles);
+        if cli.notify {
+            notify(&format!("Time for a {}.", break_label.to_lowercase()));
+        }
+        let outcome = run_phase(
+            terminal,
+            cli,
+            Some(short_break),
+            false,
+            Some(PhaseLabel { text: break_label }),
+            None,
+        )?;
+        if let PhaseOutcome::Quit = outcome {
+            return Ok(());
+        }
+    }
+
     if cli.notify {
-        notify(&format!("{} is over!", name));
+        notify("Time for a long break.");
+    }
+    let outcome = run_phase(
+        terminal,
+        cli,
+        Some(long_break),
+        false,
+        Some(PhaseLabel {
+            text: "Long break".to_owned(),
+        }),
+        None,
+    )?;
+    if let PhaseOutcome::Quit = outcome {
+        return Ok(());
     }
+
+    if cli.notify {
+        notify("Pomodoro session complete!");
+    }
+    play_sound(cli);
     Ok(())
 }
 
-pub fn draw_timer(
-    frame: &mut Frame<'_>,
+/// Runs a `timer "10m tea, 5m steep, 2m cool"`-style sequence: each labeled
+/// segment runs back-to-back, notifying at every boundary with the
+/// segment's label, with the widget tracking progress across the whole
+/// sequence in addition to the current segment.
+fn run_sequence<B: Backend>(
+    cli: &Cli,
+    terminal: &mut Terminal<B>,
+    segments: Vec<(String, time::Duration)>,
+) -> AppError {
+    let total: time::Duration = segments.iter().map(|(_, duration)| *duration).sum();
+    let mut elapsed_before = time::Duration::ZERO;
+
+    for (index, (label, duration)) in segments.iter().enumerate() {
+        if cli.notify {
+            notify(&format!("{} ({}/{})", label, index + 1, segments.len()));
+        }
+        let outcome = run_phase(
+            terminal,
+            cli,
+            Some(*duration),
+            false,
+            Some(PhaseLabel {
+                text: format!("{} ({}/{})", label, index + 1, segments.len()),
+            }),
+            Some(SessionProgress {
+                elapsed_before,
+                total,
+            }),
+        )?;
+        if let PhaseOutcome::Quit = outcome {
+            return Ok(());
+        }
+        elapsed_before += *duration;
+    }
+
+    if cli.notify {
+        notify("Sequence complete!");
+    }
+    play_sound(cli);
+    Ok(())
+}
+
+/// The parts of `draw_timer`'s state that change every tick, bundled up so
+/// the function doesn't accumulate an ever-growing flat argument list.
+struct TimerState {
     percent: u16,
-    time_left: time::Duration,
+    displayed: time::Duration,
+    counting_up: bool,
+    paused: bool,
+    overall_percent: Option<u16>,
+}
+
+fn draw_timer(
+    frame: &mut Frame<'_>,
+    state: TimerState,
     started_at: DateTime<Local>,
     cli: &Cli,
+    phase: Option<&PhaseLabel>,
 ) {
     let steps = 100;
     let base = (102, 63, 242);
@@ -175,11 +513,15 @@ pub fn draw_timer(
 
     let timer = Timer {
         gradient,
-        percent,
-        time_left,
+        percent: state.percent,
+        displayed: state.displayed,
+        counting_up: state.counting_up,
+        paused: state.paused,
         start: started_at,
         name: cli.name.clone(),
         format_12h: cli.format == "12h",
+        phase_label: phase.map(|p| p.text.clone()),
+        overall_percent: state.overall_percent,
     };
     let render_area = Rect::new(0, 0, frame.size().width, 1);
     frame.render_widget(timer, render_area);
@@ -200,10 +542,14 @@ pub fn exit<B: Backend>(terminal: &mut Terminal<B>) -> io::Result<()> {
 pub struct Timer {
     gradient: Vec<Color>,
     percent: u16,
-    time_left: time::Duration,
+    displayed: time::Duration,
+    counting_up: bool,
+    paused: bool,
     start: DateTime<Local>,
     name: Option<String>,
     format_12h: bool,
+    phase_label: Option<String>,
+    overall_percent: Option<u16>,
 }
 
 impl Widget for Timer {
@@ -213,20 +559,49 @@ impl Widget for Timer {
             None => "Timer".to_owned(),
         };
         let style: Style = Style::default();
-        let time_left = if self.time_left.as_secs_f32() < 1.0 {
-            format!("{:.2}s", self.time_left.as_secs_f32())
+        let displayed = if self.displayed.as_secs_f32() < 1.0 {
+            format!("{:.2}s", self.displayed.as_secs_f32())
         } else {
             format!(
                 "{:02}h:{:02}m:{:02}s",
-                self.time_left.as_secs() / 3600,
-                self.time_left.as_secs() / 60 % 60,
-                self.time_left.as_secs() % 60
+                self.displayed.as_secs() / 3600,
+                self.displayed.as_secs() / 60 % 60,
+                self.displayed.as_secs() % 60
             )
         };
+        let label = if self.counting_up {
+            "Elapsed"
+        } else {
+            "Time left"
+        };
         let format = if self.format_12h { "%r" } else { "%T" };
         let started_at = format!("Started at: {}", self.start.format(format));
         buf.set_string(0, 1, started_at, style);
-        buf.set_string(0, 2, format!("Time left: {}", time_left), style);
+        let time_line = match self.overall_percent {
+            Some(overall_percent) => {
+                format!("{}: {} (Overall: {}%)", label, displayed, overall_percent)
+            }
+            None => format!("{}: {}", label, displayed),
+        };
+        buf.set_string(0, 2, time_line, style);
+        if let Some(phase) = &self.phase_label {
+            let phase: String = phase.chars().take(area.width as usize).collect();
+            buf.set_string(
+                area.width.saturating_sub(phase.len() as u16),
+                1,
+                phase,
+                style.bold(),
+            );
+        }
+        if self.paused {
+            let paused = "PAUSED";
+            buf.set_string(
+                area.width.saturating_sub(paused.len() as u16),
+                2,
+                paused,
+                style.bold().fg(Color::Yellow),
+            );
+        }
 
         Block::default()
             .title(format!("{}\n", title))
@@ -0,0 +1,57 @@
+use std::fs::File;
+use std::io::BufReader;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+use crossterm::event;
+use rodio::source::{SineWave, Source};
+use rodio::{Decoder, OutputStream, Sink};
+
+use crate::SHOULD_QUIT;
+
+/// Plays `path` (or a built-in chime if `None`) at `volume`. Playback errors
+/// are ignored, mirroring the existing `notify()` posture: a terminal
+/// without working audio shouldn't interrupt the timer.
+pub fn play(path: Option<&str>, volume: f32) {
+    let _ = try_play(path, volume);
+}
+
+fn try_play(path: Option<&str>, volume: f32) -> Result<(), Box<dyn std::error::Error>> {
+    let (_stream, stream_handle) = OutputStream::try_default()?;
+    let sink = Sink::try_new(&stream_handle)?;
+    sink.set_volume(volume);
+
+    match path {
+        Some(path) => {
+            let file = File::open(path)?;
+            let source = Decoder::new(BufReader::new(file))?;
+            sink.append(source);
+        }
+        None => {
+            let chime = SineWave::new(880.0)
+                .take_duration(Duration::from_millis(300))
+                .amplify(0.20);
+            sink.append(chime);
+        }
+    }
+
+    // Poll instead of `sink.sleep_until_end()`, which would block the whole
+    // process (including the SIGINT/SIGTERM handler's quit flag and
+    // q/Esc) for the length of the sound file.
+    while !sink.empty() {
+        if SHOULD_QUIT.load(Ordering::SeqCst) {
+            break;
+        }
+        if event::poll(Duration::from_millis(20))? {
+            if let event::Event::Key(event::KeyEvent { code, .. }) = event::read()? {
+                match code {
+                    event::KeyCode::Esc | event::KeyCode::Char('q') | event::KeyCode::Char('Q') => {
+                        break;
+                    }
+                    _ => (),
+                }
+            }
+        }
+    }
+    Ok(())
+}